@@ -29,6 +29,16 @@ pub struct ConfigDirs {
     /// If `true`, `/etc` has already been added
     #[cfg(unix)]
     added_etc: bool,
+    /// If `true`, `$XDG_CONFIG_DIRS` (defaulting to `/etc/xdg`) has already been added
+    #[cfg(unix)]
+    added_xdg_dirs: bool,
+
+    /// If `true`, paths are deduped by their canonical form instead of by string equality.
+    dedup_canonical: bool,
+
+    /// If `true`, `$HOME/Library/Application Support` has already been added
+    #[cfg(target_os = "macos")]
+    added_macos_app_support: bool,
 }
 
 impl ConfigDirs {
@@ -46,6 +56,11 @@ impl ConfigDirs {
             added_platform: false,
             #[cfg(unix)]
             added_etc: false,
+            #[cfg(unix)]
+            added_xdg_dirs: false,
+            dedup_canonical: false,
+            #[cfg(target_os = "macos")]
+            added_macos_app_support: false,
         }
     }
 
@@ -190,6 +205,41 @@ impl ConfigDirs {
         self._add_path(path, true)
     }
 
+    /// Enables or disables deduplication by canonical path, instead of the default string equality.
+    ///
+    /// # Behaviour
+    ///
+    /// When enabled, every path given to the `add_*` methods from now on is resolved with
+    /// [`std::fs::canonicalize`] and skipped if an already-stored path resolves to the same
+    /// canonical target (e.g. a symlinked directory and the directory it points to). Canonicalization
+    /// failures (the path does not exist yet) fall back gracefully to plain string equality, so
+    /// nonexistent-but-intended directories are still recorded.
+    ///
+    /// This does **not** retroactively dedup paths already present when called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_finder::ConfigDirs;
+    ///
+    /// let root = std::env::temp_dir().join("config-finder-doctest-dedup_canonical");
+    /// std::fs::create_dir_all(root.join(".config")).unwrap();
+    /// std::fs::create_dir_all(root.join("sub")).unwrap();
+    /// let roundabout = root.join("sub/../.config"); // Same directory, different string
+    ///
+    /// let mut cd = ConfigDirs::empty();
+    /// cd.dedup_canonical(true);
+    /// cd.add_path(&root).add_path(roundabout);
+    /// assert_eq!(cd.paths().len(), 1);
+    ///
+    /// std::fs::remove_dir_all(&root).unwrap();
+    /// ```
+    #[inline]
+    pub fn dedup_canonical(&mut self, enabled: bool) -> &mut Self {
+        self.dedup_canonical = enabled;
+        self
+    }
+
     /// Adds all the paths starting from `start` and going up until a parent is out of `container`.
     ///
     /// This *includes* `container`.
@@ -313,6 +363,52 @@ impl ConfigDirs {
         self
     }
 
+    /// Adds every entry of a `PATH`-style environment variable to the list of paths to check.
+    ///
+    /// This is meant for applications that want to let users point at extra config roots through a
+    /// single environment variable, the same way `RUST_PATH` works for `rustpkg`.
+    ///
+    /// # Behaviour
+    ///
+    /// The variable is split using the platform's usual separator ([`std::env::split_paths`]: `:` on
+    /// Unix, `;` on Windows). Each entry then goes through [`Self::add_path()`], so `.config` is
+    /// appended and duplicates are skipped just the same. Empty or non-absolute entries are ignored.
+    ///
+    /// If the variable is not set, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use config_finder::ConfigDirs;
+    ///
+    /// std::env::set_var("MYAPP_CONFIG_PATH", "/a:/b:relative:/c");
+    ///
+    /// let mut cd = ConfigDirs::empty();
+    /// cd.add_paths_from_env("MYAPP_CONFIG_PATH");
+    /// assert_eq!(cd.paths(), &[
+    ///     PathBuf::from("/a/.config"),
+    ///     PathBuf::from("/b/.config"),
+    ///     PathBuf::from("/c/.config"),
+    /// ]); // "relative" was skipped since it is not absolute
+    ///
+    /// std::env::remove_var("MYAPP_CONFIG_PATH");
+    /// ```
+    pub fn add_paths_from_env(&mut self, var: impl AsRef<OsStr>) -> &mut Self {
+        if let Some(value) = std::env::var_os(var.as_ref()) {
+            for path in std::env::split_paths(&value) {
+                if path.as_os_str().is_empty() || path.is_absolute() == false {
+                    continue;
+                }
+
+                self._add_path(path, true);
+            }
+        }
+
+        self
+    }
+
     /// Adds the current directory to the list of paths to search in.
     ///
     /// # Errors
@@ -372,6 +468,114 @@ impl ConfigDirs {
         }
         self
     }
+
+    /// Adds the system config directories listed in `$XDG_CONFIG_DIRS` to the list of paths to
+    /// check, if not previously added.
+    ///
+    /// # Behaviour
+    ///
+    /// Per the [XDG base directory spec], `$XDG_CONFIG_DIRS` is a colon-separated, preference-ordered
+    /// list of system config roots, defaulting to `/etc/xdg` when unset. Each entry is added in order;
+    /// empty or non-absolute entries are skipped. This method will **not** add `.config`, unlike
+    /// [`Self::add_path()`].
+    ///
+    /// [XDG base directory spec]: https://specifications.freedesktop.org/basedir-spec/basedir-spec-latest.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use config_finder::ConfigDirs;
+    ///
+    /// // With `XDG_CONFIG_DIRS` unset
+    /// std::env::remove_var("XDG_CONFIG_DIRS");
+    /// let mut cd = ConfigDirs::empty();
+    /// cd.add_xdg_config_dirs();
+    /// assert_eq!(cd.paths(), &[PathBuf::from("/etc/xdg")]);
+    ///
+    /// // With `XDG_CONFIG_DIRS` set
+    /// std::env::set_var("XDG_CONFIG_DIRS", "/etc/xdg/first:relative:/etc/xdg/second");
+    /// let mut cd = ConfigDirs::empty();
+    /// cd.add_xdg_config_dirs()
+    ///   .add_xdg_config_dirs(); // Adding twice does not affect the final list
+    /// assert_eq!(cd.paths(), &[
+    ///     PathBuf::from("/etc/xdg/first"),
+    ///     PathBuf::from("/etc/xdg/second"), // "relative" was skipped since it is not absolute
+    /// ]);
+    /// std::env::remove_var("XDG_CONFIG_DIRS");
+    /// ```
+    pub fn add_xdg_config_dirs(&mut self) -> &mut Self {
+        if self.added_xdg_dirs {
+            return self;
+        }
+
+        // We don't set `self.added_xdg_dirs` unconditionally because the environment can change
+        // between the failing call and the next one (which may succeed and then set to true), see
+        // `add_platform_config_dir` above for the same reasoning.
+
+        match std::env::var_os("XDG_CONFIG_DIRS") {
+            Some(value) => {
+                for path in std::env::split_paths(&value) {
+                    if path.as_os_str().is_empty() || path.is_absolute() == false {
+                        continue;
+                    }
+
+                    self._add_path(path, false);
+                    self.added_xdg_dirs = true;
+                }
+            }
+            None => {
+                self._add_path("/etc/xdg", false);
+                self.added_xdg_dirs = true;
+            }
+        }
+
+        self
+    }
+}
+
+/// macOS-only methods
+#[cfg(target_os = "macos")]
+impl ConfigDirs {
+    /// Adds the native `$HOME/Library/Application Support` directory to the list of paths to check,
+    /// if not previously added.
+    ///
+    /// This crate otherwise maps macOS onto `~/.config` like other Unix platforms (see
+    /// [`Self::add_platform_config_dir()`]), which suits most CLI tools. GUI-adjacent applications, or
+    /// anything integrating with other macOS software, may instead want this native location. Use
+    /// this method to offer that choice instead of hardcoding one layout.
+    ///
+    /// # Behaviour
+    ///
+    /// This method will **not** add `.config`, unlike [`Self::add_path()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use config_finder::ConfigDirs;
+    ///
+    /// std::env::set_var("HOME", "/Users/alice");
+    ///
+    /// let mut cd = ConfigDirs::empty();
+    /// cd.add_macos_app_support_dir()
+    ///   .add_macos_app_support_dir(); // Adding twice does not affect the final list
+    /// assert_eq!(cd.paths(), &[PathBuf::from("/Users/alice/Library/Application Support")]);
+    /// ```
+    pub fn add_macos_app_support_dir(&mut self) -> &mut Self {
+        if self.added_macos_app_support {
+            return self;
+        }
+
+        if let Some(home) = dirs_sys::home_dir().filter(|p| p.is_absolute()) {
+            self._add_path(home.join("Library").join("Application Support"), false);
+            self.added_macos_app_support = true;
+        }
+
+        self
+    }
 }
 
 /// Private methods
@@ -390,7 +594,18 @@ impl ConfigDirs {
                 Cow::Owned(pr.join(".config"))
             };
 
-            if this.paths.iter().all(|p| p != &path) {
+            let is_new = if this.dedup_canonical {
+                match std::fs::canonicalize(&path) {
+                    Ok(canonical) => this.paths.iter().all(|p| {
+                        p != path.as_ref() && std::fs::canonicalize(p).map_or(true, |c| c != canonical)
+                    }),
+                    Err(_) => this.paths.iter().all(|p| p != &path),
+                }
+            } else {
+                this.paths.iter().all(|p| p != &path)
+            };
+
+            if is_new {
                 this.paths.push(path.into_owned());
             }
         }
@@ -418,6 +633,57 @@ impl<'c> ConfigCandidates<'c> {
             paths: paths.iter(),
         }
     }
+
+    /// Filters this iterator down to the candidates that actually exist on disk.
+    ///
+    /// # Behaviour
+    ///
+    /// For each [`WithLocal`] candidate, [`WithLocal::local_path()`] is checked first so a
+    /// machine-local override wins over the normal form, then [`WithLocal::path()`]. The check uses
+    /// [`std::fs::symlink_metadata`] so a dangling symlink still counts as found instead of being
+    /// silently skipped. This stays lazy: nothing is touched until the returned iterator is driven.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use config_finder::{ConfigDirs, EntryType};
+    ///
+    /// let dir = std::env::temp_dir().join("config-finder-doctest-existing");
+    /// std::fs::create_dir_all(dir.join(".config/my-app")).unwrap();
+    /// std::fs::write(dir.join(".config/my-app/main.kdl"), "").unwrap();
+    ///
+    /// let mut cd = ConfigDirs::empty();
+    /// let found: Vec<_> = cd.add_path(&dir).search("my-app", "main", "kdl").existing().collect();
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].path(), dir.join(".config/my-app/main.kdl"));
+    /// assert_eq!(found[0].entry_type(), EntryType::File);
+    ///
+    /// // A symlink to a directory is reported as a directory, not a file.
+    /// // (Creating a directory symlink needs elevated privileges on Windows, so this is Unix-only;
+    /// // the classification logic itself is platform-independent.)
+    /// #[cfg(unix)] {
+    ///     let link = dir.join(".config/my-app/linked");
+    ///     std::os::unix::fs::symlink(dir.join(".config/my-app"), &link).unwrap();
+    ///
+    ///     let mut cd = ConfigDirs::empty();
+    ///     let found = cd.add_path(&dir).search("my-app", "linked", "").first_existing().unwrap();
+    ///     assert_eq!(found.entry_type(), EntryType::Dir);
+    /// }
+    ///
+    /// std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn existing(self) -> impl Iterator<Item = ExistingConfig> + 'c {
+        self.filter_map(|candidate| ExistingConfig::probe(&candidate))
+    }
+
+    /// Returns the first candidate that actually exists on disk.
+    ///
+    /// This is a convenience shorthand for `self.existing().next()`; see [`Self::existing()`] for the
+    /// exact lookup order and behaviour.
+    #[inline]
+    pub fn first_existing(self) -> Option<ExistingConfig> {
+        self.existing().next()
+    }
 }
 
 impl Iterator for ConfigCandidates<'_> {
@@ -470,6 +736,89 @@ impl ExactSizeIterator for ConfigCandidates<'_> {}
 
 impl FusedIterator for ConfigCandidates<'_> {}
 
+/// A config candidate that was found on disk, yielded by [`ConfigCandidates::existing()`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExistingConfig {
+    /// The path that was found to exist.
+    path: PathBuf,
+    /// Which of the candidate's two forms matched.
+    matched: MatchedForm,
+    /// Whether the matched path is a file or a directory.
+    entry_type: EntryType,
+}
+
+impl ExistingConfig {
+    /// The path that was found to exist.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Which of the candidate's two forms ([`WithLocal::path()`] or [`WithLocal::local_path()`])
+    /// matched.
+    #[inline]
+    pub fn matched(&self) -> MatchedForm {
+        self.matched
+    }
+
+    /// Whether the matched path is a file or a directory.
+    #[inline]
+    pub fn entry_type(&self) -> EntryType {
+        self.entry_type
+    }
+
+    fn probe(candidate: &WithLocal) -> Option<Self> {
+        if let Ok(meta) = std::fs::symlink_metadata(candidate.local_path()) {
+            return Some(Self::new(candidate.local_path(), MatchedForm::Local, &meta));
+        }
+
+        if let Ok(meta) = std::fs::symlink_metadata(candidate.path()) {
+            return Some(Self::new(candidate.path(), MatchedForm::Normal, &meta));
+        }
+
+        None
+    }
+
+    fn new(path: &Path, matched: MatchedForm, meta: &std::fs::Metadata) -> Self {
+        // `meta` comes from `symlink_metadata`, so a symlink's own type is never a file or a
+        // directory: resolve it through `metadata` to classify what it points at instead, falling
+        // back to `File` for a dangling symlink.
+        let entry_type = if meta.is_symlink() {
+            std::fs::metadata(path).map_or(EntryType::File, |resolved| {
+                if resolved.is_dir() { EntryType::Dir } else { EntryType::File }
+            })
+        } else if meta.is_dir() {
+            EntryType::Dir
+        } else {
+            EntryType::File
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            matched,
+            entry_type,
+        }
+    }
+}
+
+/// Which form of a [`WithLocal`] candidate was matched by [`ConfigCandidates::existing()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchedForm {
+    /// [`WithLocal::local_path()`] is the one that was found.
+    Local,
+    /// [`WithLocal::path()`] is the one that was found.
+    Normal,
+}
+
+/// Whether an [`ExistingConfig`] is a file or a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntryType {
+    /// The matched path is a file (or a symlink to one), or a dangling symlink.
+    File,
+    /// The matched path is a directory (or a symlink to one).
+    Dir,
+}
+
 /// Stores both the normal and local form a configuration path.
 ///
 /// The local form has `.local` inserted just before the extension: `cli-app.kdl` has the local form